@@ -1,6 +1,6 @@
-use ruff_diagnostics::{AlwaysFixableViolation, Diagnostic, Edit, Fix};
+use ruff_diagnostics::{Diagnostic, Edit, Fix, Violation};
 use ruff_macros::{derive_message_formats, violation};
-use ruff_python_ast::{self as ast};
+use ruff_python_ast::{self as ast, Stmt};
 use ruff_text_size::{Ranged, TextRange};
 
 use crate::checkers::ast::Checker;
@@ -23,29 +23,49 @@ use crate::checkers::ast::Checker;
 /// num = Decimal("1.2345")
 /// ```
 ///
+/// This also applies when the float is assigned to a variable, or passed as the `value` keyword argument, before
+/// being passed to `Decimal`, as long as the variable has a single, unambiguous binding:
+/// ```python
+/// num = 1.2345
+/// dec = Decimal(num)
+/// ```
+///
 /// ## Fix Safety
 /// This rule's fix is marked as unsafe because it changes the underlying value of the `Decimal` instance that is
-/// constructed. This can lead to unexpected behavior if your program relies on the previous imprecise value.
+/// constructed: `Decimal(1.2345)` doesn't actually store `1.2345`, it stores whatever binary64 value is nearest to
+/// it, and the fix switches to the exact decimal value that was written. This can lead to unexpected behavior if
+/// your program relies on the previous imprecise value; the diagnostic spells out that stored value so you can
+/// tell whether the difference matters here.
+///
+/// No fix is offered when the float literal is bound to a variable before being passed to `Decimal`, since rewriting
+/// the distant assignment is riskier than rewriting the literal in place.
 #[violation]
-pub struct DecimalFromFloatLiteral;
+pub struct DecimalFromFloatLiteral {
+    exact_value: Option<String>,
+    /// Whether this diagnostic carries a fix. `false` for the indirect-binding case, where the float literal lives
+    /// in a separate assignment that it isn't safe to rewrite from here.
+    has_fix: bool,
+}
 
-impl AlwaysFixableViolation for DecimalFromFloatLiteral {
+impl Violation for DecimalFromFloatLiteral {
     #[derive_message_formats]
     fn message(&self) -> String {
-        format!(r#"`Decimal()` called with float literal argument"#)
+        match &self.exact_value {
+            Some(exact_value) => format!(
+                "`Decimal()` called with float literal argument; the value currently stored is `{exact_value}`, not the literal as written"
+            ),
+            None => format!(r#"`Decimal()` called with float literal argument"#),
+        }
     }
 
-    fn fix_title(&self) -> String {
-        "Use a string literal instead".into()
+    fn fix_title(&self) -> Option<String> {
+        self.has_fix
+            .then(|| "Use a string literal instead".to_string())
     }
 }
 
 /// RUF032: `Decimal()` called with float literal argument
 pub(crate) fn decimal_from_float_literal_syntax(checker: &mut Checker, call: &ast::ExprCall) {
-    let Some(arg) = call.arguments.args.first() else {
-        return;
-    };
-
     if !checker
         .semantic()
         .resolve_qualified_name(call.func.as_ref())
@@ -54,26 +74,69 @@ pub(crate) fn decimal_from_float_literal_syntax(checker: &mut Checker, call: &as
         return;
     }
 
-    if let ast::Expr::NumberLiteral(ast::ExprNumberLiteral {
-        value: ast::Number::Float(_),
-        ..
-    }) = arg
-    {
-        let diagnostic = Diagnostic::new(DecimalFromFloatLiteral, arg.range()).with_fix(
-            fix_float_literal(arg.range(), &checker.generator().expr(arg)),
-        );
+    let Some(arg) = call
+        .arguments
+        .args
+        .first()
+        .or_else(|| call.arguments.find_keyword("value").map(|kw| &kw.value))
+    else {
+        return;
+    };
+
+    if is_float_literal(arg) {
+        let literal_text = checker.generator().expr(arg);
+        let diagnostic = Diagnostic::new(
+            DecimalFromFloatLiteral {
+                exact_value: exact_value_if_imprecise(&literal_text),
+                has_fix: true,
+            },
+            arg.range(),
+        )
+        .with_fix(fix_float_literal(arg.range(), &literal_text));
         checker.diagnostics.push(diagnostic);
-    } else if let ast::Expr::UnaryOp(ast::ExprUnaryOp { operand, .. }) = arg {
-        if let ast::Expr::NumberLiteral(ast::ExprNumberLiteral {
+        return;
+    }
+
+    // `x = 1.5; Decimal(x)` (or `Decimal(value=x)`): resolve `x` to its
+    // single unambiguous binding and check whether *that* is a float
+    // literal. We don't have a safe way to rewrite the distant assignment,
+    // so we only emit the diagnostic, without a fix.
+    let ast::Expr::Name(name) = arg else {
+        return;
+    };
+
+    let Some(binding_id) = checker.semantic().only_binding(name) else {
+        return;
+    };
+    let binding = checker.semantic().binding(binding_id);
+
+    let Some(Stmt::Assign(ast::StmtAssign { value, .. })) = binding.statement(checker.semantic())
+    else {
+        return;
+    };
+
+    if is_float_literal(value) {
+        let literal_text = checker.generator().expr(value);
+        checker.diagnostics.push(Diagnostic::new(
+            DecimalFromFloatLiteral {
+                exact_value: exact_value_if_imprecise(&literal_text),
+                has_fix: false,
+            },
+            arg.range(),
+        ));
+    }
+}
+
+/// Returns `true` if `expr` is a float literal, or a unary-negated float
+/// literal (e.g. `-0.0`).
+fn is_float_literal(expr: &ast::Expr) -> bool {
+    match expr {
+        ast::Expr::NumberLiteral(ast::ExprNumberLiteral {
             value: ast::Number::Float(_),
             ..
-        }) = operand.as_ref()
-        {
-            let diagnostic = Diagnostic::new(DecimalFromFloatLiteral, arg.range()).with_fix(
-                fix_float_literal(arg.range(), &checker.generator().expr(arg)),
-            );
-            checker.diagnostics.push(diagnostic);
-        }
+        }) => true,
+        ast::Expr::UnaryOp(ast::ExprUnaryOp { operand, .. }) => is_float_literal(operand),
+        _ => false,
     }
 }
 
@@ -81,3 +144,203 @@ fn fix_float_literal(range: TextRange, float_literal: &str) -> Fix {
     let content = format!("\"{float_literal}\"");
     Fix::unsafe_edit(Edit::range_replacement(content, range))
 }
+
+/// If `literal_text`, parsed as an `f64`, is not stored exactly as written (i.e. `Decimal(literal_text)` would
+/// silently round it), returns the exact decimal expansion of the value that's actually stored.
+fn exact_value_if_imprecise(literal_text: &str) -> Option<String> {
+    let normalized_source = literal_text.replace('_', "");
+    let value: f64 = normalized_source.parse().ok()?;
+    if !value.is_finite() {
+        return None;
+    }
+
+    let exact = exact_decimal_string(value);
+    let trimmed_literal = normalized_source
+        .trim_start_matches('+')
+        .trim_start_matches('-');
+    if normalize_decimal_spelling(trimmed_literal) == exact {
+        None
+    } else {
+        Some(if value.is_sign_negative() {
+            format!("-{exact}")
+        } else {
+            exact
+        })
+    }
+}
+
+/// Normalizes a plain decimal literal's spelling for comparison against `exact_decimal_string`'s output: expands
+/// exponent notation (e.g. `"1e10"` -> `"10000000000"`) to plain digits, since `exact_decimal_string` never
+/// produces exponential output, then strips insignificant trailing zeros from the fractional part (e.g. `"2.50"`
+/// -> `"2.5"`), so that such a literal isn't mistaken for one that loses precision.
+fn normalize_decimal_spelling(text: &str) -> String {
+    let text = expand_exponent(text);
+    let Some((int_part, frac_part)) = text.split_once('.') else {
+        return text;
+    };
+    let frac = frac_part.trim_end_matches('0');
+    if frac.is_empty() {
+        int_part.to_string()
+    } else {
+        format!("{int_part}.{frac}")
+    }
+}
+
+/// Rewrites a decimal literal's exponent notation (`"1.5e1"`, `"2E-3"`) into plain decimal digits (`"15"`,
+/// `"0.002"`), preserving the exact value: shifting the decimal point is lossless, since the literal's digits are
+/// already an exact decimal number.
+fn expand_exponent(text: &str) -> String {
+    let Some((mantissa, exponent)) = text.split_once(['e', 'E']) else {
+        return text.to_string();
+    };
+    let Ok(exponent) = exponent.parse::<i32>() else {
+        return text.to_string();
+    };
+
+    let (int_part, frac_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let digits = format!("{int_part}{frac_part}");
+    let point_pos = int_part.len() as i32 + exponent;
+
+    if point_pos <= 0 {
+        format!("0.{}{digits}", "0".repeat((-point_pos) as usize))
+    } else if (point_pos as usize) >= digits.len() {
+        format!("{digits}{}", "0".repeat(point_pos as usize - digits.len()))
+    } else {
+        let split = point_pos as usize;
+        format!("{}.{}", &digits[..split], &digits[split..])
+    }
+}
+
+/// Returns the exact (non-rounded) decimal expansion of a nonnegative `f64`'s magnitude, e.g. `0.1` expands to
+/// `0.1000000000000000055511151231257827021181583404541015625`, since every binary64 value is a dyadic rational
+/// and therefore has a finite, exact decimal representation.
+fn exact_decimal_string(value: f64) -> String {
+    let bits = value.to_bits();
+    let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+    let mantissa_bits = bits & 0x000f_ffff_ffff_ffff;
+
+    let (mantissa, exponent) = if biased_exponent == 0 {
+        // Subnormal: no implicit leading 1 bit.
+        (mantissa_bits, -1074)
+    } else {
+        (mantissa_bits | 0x0010_0000_0000_0000, biased_exponent - 1075)
+    };
+
+    if mantissa == 0 {
+        return "0".to_string();
+    }
+
+    if exponent >= 0 {
+        // `mantissa * 2^exponent` is an integer.
+        return multiply_by_two_pow(mantissa, exponent as u32);
+    }
+
+    // `mantissa * 2^exponent == (mantissa * 5^-exponent) / 10^-exponent`: compute the numerator exactly, then
+    // place the decimal point `-exponent` digits from the right.
+    let shift = (-exponent) as u32;
+    let digits = multiply_by_five_pow(mantissa, shift);
+    insert_decimal_point(&digits, shift as usize)
+}
+
+/// Returns the decimal digit string of `mantissa * 2^power`.
+fn multiply_by_two_pow(mantissa: u64, power: u32) -> String {
+    let mut digits = decimal_digits(mantissa);
+    for _ in 0..power {
+        multiply_digits_in_place(&mut digits, 2);
+    }
+    digits_to_string(&digits)
+}
+
+/// Returns the decimal digit string of `mantissa * 5^power`.
+fn multiply_by_five_pow(mantissa: u64, power: u32) -> Vec<u8> {
+    let mut digits = decimal_digits(mantissa);
+    for _ in 0..power {
+        multiply_digits_in_place(&mut digits, 5);
+    }
+    digits
+}
+
+/// Converts `n` to a little-endian vector of decimal digits.
+fn decimal_digits(mut n: u64) -> Vec<u8> {
+    if n == 0 {
+        return vec![0];
+    }
+    let mut digits = Vec::new();
+    while n > 0 {
+        digits.push((n % 10) as u8);
+        n /= 10;
+    }
+    digits
+}
+
+/// Multiplies a little-endian decimal digit vector by a small factor, in place.
+fn multiply_digits_in_place(digits: &mut Vec<u8>, factor: u8) {
+    let mut carry: u32 = 0;
+    for digit in digits.iter_mut() {
+        let product = *digit as u32 * factor as u32 + carry;
+        *digit = (product % 10) as u8;
+        carry = product / 10;
+    }
+    while carry > 0 {
+        digits.push((carry % 10) as u8);
+        carry /= 10;
+    }
+}
+
+fn digits_to_string(digits: &[u8]) -> String {
+    digits.iter().rev().map(|d| (b'0' + d) as char).collect()
+}
+
+/// Given the little-endian digits of an integer `numerator`, returns the decimal string of `numerator / 10^shift`,
+/// inserting a decimal point and zero-padding the fractional part as needed.
+fn insert_decimal_point(digits: &[u8], shift: usize) -> String {
+    let whole = digits_to_string(digits);
+    if shift == 0 {
+        return whole;
+    }
+    let with_point = if whole.len() <= shift {
+        let padding = "0".repeat(shift - whole.len());
+        format!("0.{padding}{whole}")
+    } else {
+        let split = whole.len() - shift;
+        format!("{}.{}", &whole[..split], &whole[split..])
+    };
+    // Trailing zeros in the fractional part don't change the value, just the spelling; strip them so the exact
+    // value reads the way `decimal.Decimal(x)` would print it.
+    let trimmed = with_point.trim_end_matches('0');
+    trimmed.trim_end_matches('.').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::exact_value_if_imprecise;
+
+    #[test]
+    fn exact_scientific_notation_is_not_flagged() {
+        assert_eq!(exact_value_if_imprecise("1e2"), None);
+        assert_eq!(exact_value_if_imprecise("1.5e1"), None);
+        assert_eq!(exact_value_if_imprecise("2e10"), None);
+        assert_eq!(exact_value_if_imprecise("1e22"), None);
+    }
+
+    #[test]
+    fn trailing_zeros_are_not_flagged() {
+        assert_eq!(exact_value_if_imprecise("2.50"), None);
+    }
+
+    #[test]
+    fn imprecise_literal_reports_exact_stored_value() {
+        assert_eq!(
+            exact_value_if_imprecise("0.1"),
+            Some("0.1000000000000000055511151231257827021181583404541015625".to_string())
+        );
+    }
+
+    #[test]
+    fn negative_literal_keeps_its_sign() {
+        assert_eq!(
+            exact_value_if_imprecise("-0.1"),
+            Some("-0.1000000000000000055511151231257827021181583404541015625".to_string())
+        );
+    }
+}
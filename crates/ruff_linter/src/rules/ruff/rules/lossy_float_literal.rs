@@ -0,0 +1,284 @@
+use std::fmt;
+
+use ruff_diagnostics::{AlwaysFixableViolation, Diagnostic, Edit, Fix};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for float literals whose written digits do not survive the round
+/// trip through an IEEE-754 binary64 (`f64`) value: literals for which the
+/// shortest spelling of the stored value denotes a different number than the
+/// one written. Literals like `0.1`, whose shortest round-tripping spelling
+/// is the literal itself, are not flagged.
+///
+/// ## Why is this bad?
+/// CPython stores every float as a binary64 value, so literals with more
+/// significant digits than a binary64 can hold are silently rounded. This
+/// is either misleading (the extra digits are never used, e.g.
+/// `1.123456789012345678`) or outright lossy (the literal has no more digits
+/// than the stored value's shortest spelling, yet denotes a different number,
+/// e.g. `9007199254740993.0`, which rounds to `9007199254740992.0`). In both
+/// cases, the literal implies
+/// a precision that the runtime value does not have.
+///
+/// ## Example
+/// ```python
+/// x = 0.123_456_789_012_345_678_9
+/// ```
+///
+/// Use instead:
+/// ```python
+/// x = 0.12345678901234568
+/// ```
+///
+/// ## Fix safety
+/// This rule's fix is safe: it replaces the literal with the shortest
+/// string that round-trips to the same `f64` value, so the runtime value
+/// of the expression is unchanged. The replacement is always spelled as a
+/// float (e.g. `9007199254740992.0`, never the bare integer
+/// `9007199254740992`), so the fix cannot turn a `float` into an `int`.
+#[violation]
+pub struct LossyFloatLiteral {
+    kind: LossyFloatLiteralKind,
+    replacement: String,
+}
+
+impl AlwaysFixableViolation for LossyFloatLiteral {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let LossyFloatLiteral { kind, replacement } = self;
+        match kind {
+            LossyFloatLiteralKind::Excessive => format!(
+                "Float literal has more precision than a float can represent; the stored value is `{replacement}`"
+            ),
+            LossyFloatLiteralKind::Lossy => format!(
+                "Float literal loses precision when stored; the stored value is `{replacement}`"
+            ),
+        }
+    }
+
+    fn fix_title(&self) -> String {
+        "Replace with the literal's shortest round-tripping representation".to_string()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum LossyFloatLiteralKind {
+    /// The literal carries more significant digits than the shortest
+    /// round-tripping representation of the parsed value, but the parsed
+    /// value is the one the author intended (e.g. trailing digits that
+    /// don't change the nearest `f64`).
+    Excessive,
+    /// The literal has no more significant digits than the shortest
+    /// round-tripping representation, yet denotes a different number than
+    /// the one stored (e.g. whole numbers beyond 2**53).
+    Lossy,
+}
+
+impl fmt::Display for LossyFloatLiteralKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LossyFloatLiteralKind::Excessive => write!(f, "excessive precision"),
+            LossyFloatLiteralKind::Lossy => write!(f, "lossy"),
+        }
+    }
+}
+
+/// RUF034: Float literal loses precision when parsed as a binary64
+pub(crate) fn lossy_float_literal(checker: &mut Checker, expr: &ast::Expr) {
+    let ast::Expr::NumberLiteral(ast::ExprNumberLiteral {
+        value: ast::Number::Float(_),
+        range,
+    }) = expr
+    else {
+        return;
+    };
+
+    // f-string format specs are parsed as separate expressions by the time
+    // they reach the checker, but literals that appear as the *replacement
+    // field* of an f-string (e.g. `f"{1.0:.2f}"`) are real number literals
+    // and should still be checked; only the format-spec text itself, which
+    // is not an `Expr::NumberLiteral`, is excluded.
+    let source = checker.locator().slice(*range);
+
+    let Some((kind, replacement)) = classify(source) else {
+        return;
+    };
+
+    let diagnostic = Diagnostic::new(
+        LossyFloatLiteral {
+            kind,
+            replacement: replacement.clone(),
+        },
+        *range,
+    )
+    .with_fix(Fix::safe_edit(Edit::range_replacement(replacement, *range)));
+    checker.diagnostics.push(diagnostic);
+}
+
+/// Classify a float literal's source text, returning the kind of precision
+/// loss (if any) along with the shortest round-tripping replacement text.
+fn classify(source: &str) -> Option<(LossyFloatLiteralKind, String)> {
+    let normalized_source = source.replace('_', "");
+
+    // `inf`/`nan` can't appear as float literal spellings in Python source
+    // (they're names, not literals), but guard anyway for safety.
+    let lower = normalized_source.to_ascii_lowercase();
+    if lower.contains("inf") || lower.contains("nan") {
+        return None;
+    }
+
+    let parsed: f64 = normalized_source.parse().ok()?;
+    if !parsed.is_finite() {
+        return None;
+    }
+
+    let shortest = format!("{parsed}");
+
+    // Rust never renders `{parsed}` with an exponent, so the literal's own
+    // exponent notation (`1e2`, `1.5e1`, `2e10`, ...) has to be expanded to
+    // plain decimal digits before it can be compared; otherwise `1e2` and
+    // `"100"` look different even though they denote the same value.
+    let literal_plain = expand_exponent(&normalized_source);
+
+    // If the literal, once insignificant trailing zeros are dropped, is
+    // already the shortest round-tripping spelling, nothing was lost: e.g.
+    // `2.50` and `2.5` denote the same value, and `0.1`'s shortest form is
+    // `0.1` even though no binary64 stores `0.1` exactly. Comparing digit
+    // *counts* instead of the values themselves would miss cases like
+    // `9007199254740993.0`, whose 16-digit source and 16-digit stored value
+    // (`9007199254740992`) are genuinely different numbers.
+    if trim_fraction(&literal_plain) == trim_fraction(&shortest) {
+        return None;
+    }
+
+    // Distinguish "the literal simply wrote out more significant digits than
+    // the shortest round-tripping form needs" (misleading but harmless - the
+    // extra digits are the ones that got rounded away) from "the literal has
+    // no more digits than the shortest form, yet still denotes a different
+    // number" (harmful - e.g. whole numbers at or beyond 2**53, where
+    // consecutive integers stop being exactly representable).
+    let kind = if significant_digits(&literal_plain) > significant_digits(&shortest) {
+        LossyFloatLiteralKind::Excessive
+    } else {
+        LossyFloatLiteralKind::Lossy
+    };
+
+    Some((kind, ensure_float_spelling(&shortest)))
+}
+
+/// Rewrites a decimal literal's exponent notation (`1.5e1`, `2E-3`) into
+/// plain decimal digits (`15`, `0.002`), preserving the exact value: shifting
+/// the decimal point is lossless, since the literal's digits are already an
+/// exact decimal number.
+fn expand_exponent(text: &str) -> String {
+    let Some((mantissa, exponent)) = text.split_once(['e', 'E']) else {
+        return text.to_string();
+    };
+    let Ok(exponent) = exponent.parse::<i32>() else {
+        return text.to_string();
+    };
+
+    let negative = mantissa.starts_with('-');
+    let mantissa = mantissa.trim_start_matches(['+', '-']);
+    let (int_part, frac_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let digits = format!("{int_part}{frac_part}");
+    let point_pos = int_part.len() as i32 + exponent;
+
+    let magnitude = if point_pos <= 0 {
+        format!("0.{}{digits}", "0".repeat((-point_pos) as usize))
+    } else if (point_pos as usize) >= digits.len() {
+        format!("{digits}{}", "0".repeat(point_pos as usize - digits.len()))
+    } else {
+        let split = point_pos as usize;
+        format!("{}.{}", &digits[..split], &digits[split..])
+    };
+
+    if negative {
+        format!("-{magnitude}")
+    } else {
+        magnitude
+    }
+}
+
+/// Strips insignificant zeros from a plain decimal string: leading zeros of
+/// the integer part and trailing zeros of the fractional part (e.g. `"2.50"`
+/// -> `"2.5"`, `".5"` -> `"0.5"`, `"100"` -> `"100"`).
+fn trim_fraction(text: &str) -> String {
+    let (int_part, frac_part) = text.split_once('.').unwrap_or((text, ""));
+    let int_part = match int_part.trim_start_matches('0') {
+        "" => "0",
+        trimmed => trimmed,
+    };
+    let frac = frac_part.trim_end_matches('0');
+    if frac.is_empty() {
+        int_part.to_string()
+    } else {
+        format!("{int_part}.{frac}")
+    }
+}
+
+/// Counts the significant (non-zero-padding) decimal digits in a plain
+/// decimal string, ignoring sign and decimal point.
+fn significant_digits(text: &str) -> usize {
+    let digits: String = text
+        .chars()
+        .filter(char::is_ascii_digit)
+        .collect::<String>();
+    let digits = digits.trim_start_matches('0').trim_end_matches('0');
+    digits.len().max(1)
+}
+
+/// Ensures `text` (a number formatted by `format!("{f}")`) is spelled as a
+/// float literal rather than an integer literal, so replacing a float
+/// literal with it can never change the expression's runtime type.
+fn ensure_float_spelling(text: &str) -> String {
+    if text.contains(['.', 'e', 'E']) {
+        text.to_string()
+    } else {
+        format!("{text}.0")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{classify, LossyFloatLiteralKind};
+
+    #[test]
+    fn exact_scientific_notation_is_not_flagged() {
+        assert_eq!(classify("1e2"), None);
+        assert_eq!(classify("1.5e1"), None);
+        assert_eq!(classify("2e10"), None);
+        assert_eq!(classify("1e300"), None);
+    }
+
+    #[test]
+    fn ordinary_decimal_fractions_are_not_flagged() {
+        assert_eq!(classify("0.1"), None);
+        assert_eq!(classify("2.50"), None);
+        assert_eq!(classify("100.0"), None);
+    }
+
+    #[test]
+    fn excess_precision_is_flagged_as_excessive() {
+        let (kind, replacement) = classify("0.123_456_789_012_345_678_9").unwrap();
+        assert_eq!(kind, LossyFloatLiteralKind::Excessive);
+        assert_eq!(replacement, "0.12345678901234568");
+    }
+
+    #[test]
+    fn integer_precision_loss_is_flagged_as_lossy() {
+        let (kind, replacement) = classify("9007199254740993.0").unwrap();
+        assert_eq!(kind, LossyFloatLiteralKind::Lossy);
+        assert_eq!(replacement, "9007199254740992.0");
+    }
+
+    #[test]
+    fn fix_is_never_a_bare_integer_literal() {
+        let (_, replacement) = classify("100.000000000000000001").unwrap();
+        assert!(replacement.contains('.'));
+    }
+}
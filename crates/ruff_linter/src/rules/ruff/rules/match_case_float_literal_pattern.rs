@@ -0,0 +1,150 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+use ruff_python_ast::{self as ast};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `match` statement patterns that compare against a float literal.
+///
+/// ## Why is this bad?
+/// Float literals are represented using limited-precision IEEE-754 binary64
+/// values, so a literal written in source rarely matches the exact value
+/// stored at runtime. Since `case` patterns compare by equality, a pattern
+/// like `case 1.1:` can silently fail to match a value that was computed
+/// rather than typed literally, and the arm is never taken.
+///
+/// ## Example
+/// ```python
+/// match x:
+///     case 1.1:
+///         ...
+/// ```
+///
+/// Use instead:
+/// ```python
+/// match x:
+///     case x if x == 1.1:
+///         ...
+/// ```
+///
+/// or a `Decimal`/`math.isclose` comparison, depending on the precision
+/// guarantees required.
+#[violation]
+pub struct MatchCaseFloatLiteralPattern;
+
+impl Violation for MatchCaseFloatLiteralPattern {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        format!("`match` pattern matches against a float literal, which is unlikely to be equal to the matched value")
+    }
+}
+
+/// RUF033: `match` pattern matches against a float literal
+pub(crate) fn match_case_float_literal_pattern(checker: &mut Checker, match_stmt: &ast::StmtMatch) {
+    for case in &match_stmt.cases {
+        check_pattern(checker, &case.pattern);
+    }
+}
+
+fn check_pattern(checker: &mut Checker, pattern: &ast::Pattern) {
+    match pattern {
+        ast::Pattern::MatchValue(ast::PatternMatchValue { value, range, .. }) => {
+            if is_float_literal_expr(value) {
+                checker
+                    .diagnostics
+                    .push(Diagnostic::new(MatchCaseFloatLiteralPattern, *range));
+            }
+        }
+        ast::Pattern::MatchMapping(ast::PatternMatchMapping { keys, patterns, .. }) => {
+            for key in keys {
+                if is_float_literal_expr(key) {
+                    checker
+                        .diagnostics
+                        .push(Diagnostic::new(MatchCaseFloatLiteralPattern, key.range()));
+                }
+            }
+            for nested in patterns {
+                check_pattern(checker, nested);
+            }
+        }
+        ast::Pattern::MatchSequence(ast::PatternMatchSequence { patterns, .. }) => {
+            for nested in patterns {
+                check_pattern(checker, nested);
+            }
+        }
+        ast::Pattern::MatchClass(ast::PatternMatchClass { arguments, .. }) => {
+            for nested in &arguments.patterns {
+                check_pattern(checker, nested);
+            }
+            for keyword in &arguments.keywords {
+                check_pattern(checker, &keyword.pattern);
+            }
+        }
+        ast::Pattern::MatchOr(ast::PatternMatchOr { patterns, .. }) => {
+            for nested in patterns {
+                check_pattern(checker, nested);
+            }
+        }
+        ast::Pattern::MatchAs(ast::PatternMatchAs { pattern, .. }) => {
+            if let Some(nested) = pattern {
+                check_pattern(checker, nested);
+            }
+        }
+        ast::Pattern::MatchStar(_) | ast::Pattern::MatchSingleton(_) => {}
+    }
+}
+
+/// Returns `true` if `expr` is a float literal, a unary-negated float literal
+/// (e.g. `-0.0`), a complex literal with a nonzero float part, or a compound
+/// complex literal (e.g. `1.5+2j`, `3+2.5j`). A compound complex literal
+/// pattern is parsed as a `BinOp` of two number literals, not as a single
+/// `Number::Complex`, since `case 1.5+2j:` combines a real-part literal and
+/// an imaginary-part literal with `+`/`-`.
+fn is_float_literal_expr(expr: &ast::Expr) -> bool {
+    match expr {
+        ast::Expr::NumberLiteral(ast::ExprNumberLiteral { value, .. }) => has_float_part(value),
+        ast::Expr::UnaryOp(ast::ExprUnaryOp { operand, .. }) => is_float_literal_expr(operand),
+        ast::Expr::BinOp(ast::ExprBinOp {
+            op: ast::Operator::Add | ast::Operator::Sub,
+            left,
+            right,
+            ..
+        }) => is_float_literal_expr(left) || is_float_literal_expr(right),
+        _ => false,
+    }
+}
+
+/// Returns `true` if `value` is a `float`, or a `complex` with a nonzero float part.
+fn has_float_part(value: &ast::Number) -> bool {
+    match value {
+        ast::Number::Float(_) => true,
+        ast::Number::Complex { imag, .. } => *imag != 0.0,
+        ast::Number::Int(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ruff_python_ast as ast;
+
+    use super::has_float_part;
+
+    #[test]
+    fn float_literals_have_a_float_part() {
+        assert!(has_float_part(&ast::Number::Float(1.1)));
+    }
+
+    #[test]
+    fn complex_literals_depend_on_the_imaginary_part() {
+        assert!(has_float_part(&ast::Number::Complex {
+            real: 0.0,
+            imag: 2.0
+        }));
+        assert!(!has_float_part(&ast::Number::Complex {
+            real: 0.0,
+            imag: 0.0
+        }));
+    }
+}